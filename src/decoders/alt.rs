@@ -0,0 +1,72 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_client::rpc_client::RpcClient;
+use solana_pubkey::Pubkey;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Slots an address lookup table remains usable after its deactivation slot,
+/// mirroring the runtime's cooldown (roughly one slot-hashes period).
+const DEACTIVATION_COOLDOWN_SLOTS: u64 = 512;
+
+/// Decodes address lookup table accounts, including the `meta` fields
+/// (deactivation/extension slots, authority) and a derived active status.
+/// The current slot is fetched once and reused across every decoded table.
+pub struct AltDecoder {
+    rpc: Rc<RpcClient>,
+    current_slot: Cell<Option<u64>>,
+}
+
+impl AltDecoder {
+    pub fn new(rpc: Rc<RpcClient>) -> Self {
+        Self {
+            rpc,
+            current_slot: Cell::new(None),
+        }
+    }
+
+    fn current_slot(&self) -> Result<u64> {
+        if let Some(slot) = self.current_slot.get() {
+            return Ok(slot);
+        }
+
+        let slot = self.rpc.get_slot()?;
+        self.current_slot.set(Some(slot));
+        Ok(slot)
+    }
+}
+
+impl AccountDecoder for AltDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        let alt = AddressLookupTable::deserialize(data)?;
+        let addresses: Vec<String> = alt
+            .addresses
+            .as_ref()
+            .iter()
+            .map(|pk| pk.to_string())
+            .collect();
+
+        let deactivation_slot = alt.meta.deactivation_slot;
+        let current_slot = self.current_slot()?;
+        let status = if deactivation_slot == u64::MAX {
+            "active"
+        } else if current_slot <= deactivation_slot.saturating_add(DEACTIVATION_COOLDOWN_SLOTS) {
+            "deactivating"
+        } else {
+            "deactivated"
+        };
+
+        Ok(json!({
+            "type": "address_lookup_table",
+            "addresses": addresses,
+            "num_addresses": addresses.len(),
+            "authority": alt.meta.authority.map(|pk| pk.to_string()),
+            "deactivation_slot": deactivation_slot,
+            "last_extended_slot": alt.meta.last_extended_slot,
+            "last_extended_slot_start_index": alt.meta.last_extended_slot_start_index,
+            "status": status,
+        }))
+    }
+}
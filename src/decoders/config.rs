@@ -0,0 +1,39 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_pubkey::Pubkey;
+
+/// Decodes native Config program accounts: a `ConfigKeys` signer list
+/// followed by an opaque, program-specific config payload.
+pub struct ConfigDecoder;
+
+impl AccountDecoder for ConfigDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        anyhow::ensure!(data.len() >= 8, "config account data too short");
+
+        let key_count = u64::from_le_bytes(data[0..8].try_into()?) as usize;
+        let max_keys = (data.len() - 8) / 33;
+        anyhow::ensure!(
+            key_count <= max_keys,
+            "config account claims {} keys but only has room for {}",
+            key_count,
+            max_keys
+        );
+
+        let mut offset = 8;
+        let mut keys = Vec::with_capacity(key_count);
+        for _ in 0..key_count {
+            anyhow::ensure!(data.len() >= offset + 33, "config account truncated");
+            let pubkey = Pubkey::try_from(&data[offset..offset + 32])?;
+            let is_signer = data[offset + 32] != 0;
+            keys.push(json!({ "pubkey": pubkey.to_string(), "is_signer": is_signer }));
+            offset += 33;
+        }
+
+        Ok(json!({
+            "type": "config",
+            "keys": keys,
+            "data_hex": hex::encode(&data[offset..]),
+        }))
+    }
+}
@@ -0,0 +1,87 @@
+//! Account decoders, keyed either by an explicit `-t` keyword or, in `auto`
+//! mode, by the owning program id (mirrors Solana's `parse_account_data`).
+
+mod alt;
+mod config;
+mod nonce;
+mod raw;
+mod stake;
+mod sysvar;
+mod token;
+mod vote;
+
+pub use alt::AltDecoder;
+pub use config::ConfigDecoder;
+pub use nonce::NonceDecoder;
+pub use raw::raw_account_json;
+pub use stake::StakeDecoder;
+pub use sysvar::SysvarDecoder;
+pub use token::TokenDecoder;
+pub use vote::VoteDecoder;
+
+use anyhow::Result;
+use solana_client::rpc_client::RpcClient;
+use solana_pubkey::Pubkey;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// This is a single-threaded CLI, so decoders don't need to cross thread
+// boundaries; `TokenDecoder` and `AltDecoder` cache RPC lookups behind
+// `Rc`/`RefCell`/`Cell`, which rules out `Send + Sync` here.
+pub trait AccountDecoder {
+    fn decode(&self, pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value>;
+}
+
+/// Resolves a decoder from an explicit `-t` keyword.
+pub fn get_decoder(
+    parser: Option<&str>,
+    rpc: Rc<RpcClient>,
+) -> Result<Option<Box<dyn AccountDecoder>>> {
+    match parser {
+        Some("alt") => Ok(Some(Box::new(AltDecoder::new(rpc)))),
+        Some("token") => Ok(Some(Box::new(TokenDecoder::new(rpc)))),
+        Some("stake") => Ok(Some(Box::new(StakeDecoder))),
+        Some("vote") => Ok(Some(Box::new(VoteDecoder))),
+        Some("nonce") => Ok(Some(Box::new(NonceDecoder))),
+        Some("config") => Ok(Some(Box::new(ConfigDecoder))),
+        Some("sysvar") => Ok(Some(Box::new(SysvarDecoder))),
+        Some(p) => anyhow::bail!("Unknown parser: {}", p),
+        None => Ok(None),
+    }
+}
+
+/// Builds the owner-id -> decoder registry used by `-t auto`.
+pub fn owner_registry(rpc: Rc<RpcClient>) -> HashMap<Pubkey, Box<dyn AccountDecoder>> {
+    let mut registry: HashMap<Pubkey, Box<dyn AccountDecoder>> = HashMap::new();
+
+    registry.insert(token_program_id(), Box::new(TokenDecoder::new(rpc)));
+    registry.insert(stake_program_id(), Box::new(StakeDecoder));
+    registry.insert(vote_program_id(), Box::new(VoteDecoder));
+    // Nonce accounts and plain wallets are both owned by the System program.
+    registry.insert(Pubkey::default(), Box::new(NonceDecoder));
+    registry.insert(config_program_id(), Box::new(ConfigDecoder));
+    registry.insert(sysvar_owner_id(), Box::new(SysvarDecoder));
+
+    registry
+}
+
+fn token_program_id() -> Pubkey {
+    Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").expect("valid pubkey")
+}
+
+fn stake_program_id() -> Pubkey {
+    Pubkey::from_str("Stake11111111111111111111111111111111111111").expect("valid pubkey")
+}
+
+fn vote_program_id() -> Pubkey {
+    Pubkey::from_str("Vote111111111111111111111111111111111111111").expect("valid pubkey")
+}
+
+fn config_program_id() -> Pubkey {
+    Pubkey::from_str("Config1111111111111111111111111111111111111").expect("valid pubkey")
+}
+
+fn sysvar_owner_id() -> Pubkey {
+    Pubkey::from_str("Sysvar1111111111111111111111111111111111111").expect("valid pubkey")
+}
@@ -0,0 +1,36 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_nonce::state::{Data, State, Versions};
+use solana_pubkey::Pubkey;
+
+/// Decodes System-program-owned accounts. Most such accounts are plain
+/// wallets with no data; durable nonce accounts carry versioned state.
+pub struct NonceDecoder;
+
+impl AccountDecoder for NonceDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        if data.is_empty() {
+            return Ok(json!({ "type": "system_account" }));
+        }
+
+        let versions: Versions = bincode::deserialize(data)?;
+        Ok(match versions.state() {
+            State::Uninitialized => json!({
+                "type": "nonce",
+                "state": "uninitialized",
+            }),
+            State::Initialized(data) => nonce_data_json(data),
+        })
+    }
+}
+
+fn nonce_data_json(data: &Data) -> serde_json::Value {
+    json!({
+        "type": "nonce",
+        "state": "initialized",
+        "authority": data.authority.to_string(),
+        "durable_nonce": data.durable_nonce.as_hash().to_string(),
+        "lamports_per_signature": data.fee_calculator.lamports_per_signature,
+    })
+}
@@ -0,0 +1,12 @@
+use base64ct::{Base64, Encoding};
+use serde_json::{json, Value};
+
+/// Fallback representation for accounts with no registered decoder.
+pub fn raw_account_json(data: &[u8]) -> Value {
+    json!({
+        "type": "raw",
+        "hex": hex::encode(data),
+        "base64": Base64::encode_string(data),
+        "size": data.len(),
+    })
+}
@@ -0,0 +1,47 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_pubkey::Pubkey;
+use solana_stake_interface::state::StakeStateV2;
+
+/// Decodes native Stake program accounts.
+pub struct StakeDecoder;
+
+impl AccountDecoder for StakeDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        let state: StakeStateV2 = bincode::deserialize(data)?;
+
+        Ok(match state {
+            StakeStateV2::Uninitialized => json!({
+                "type": "stake",
+                "state": "uninitialized",
+            }),
+            StakeStateV2::Initialized(meta) => json!({
+                "type": "stake",
+                "state": "initialized",
+                "rent_exempt_reserve": meta.rent_exempt_reserve,
+                "authorized_staker": meta.authorized.staker.to_string(),
+                "authorized_withdrawer": meta.authorized.withdrawer.to_string(),
+                "lockup_epoch": meta.lockup.epoch,
+                "lockup_unix_timestamp": meta.lockup.unix_timestamp,
+                "lockup_custodian": meta.lockup.custodian.to_string(),
+            }),
+            StakeStateV2::Stake(meta, stake, _flags) => json!({
+                "type": "stake",
+                "state": "delegated",
+                "rent_exempt_reserve": meta.rent_exempt_reserve,
+                "authorized_staker": meta.authorized.staker.to_string(),
+                "authorized_withdrawer": meta.authorized.withdrawer.to_string(),
+                "voter_pubkey": stake.delegation.voter_pubkey.to_string(),
+                "stake": stake.delegation.stake,
+                "activation_epoch": stake.delegation.activation_epoch,
+                "deactivation_epoch": stake.delegation.deactivation_epoch,
+                "credits_observed": stake.credits_observed,
+            }),
+            StakeStateV2::RewardsPool => json!({
+                "type": "stake",
+                "state": "rewards_pool",
+            }),
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_clock::Clock;
+use solana_epoch_schedule::EpochSchedule;
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+use std::str::FromStr;
+
+/// Decodes native sysvar accounts. Clock, Rent and EpochSchedule are decoded
+/// structurally; other sysvars are reported as raw hex with the name `unknown`.
+pub struct SysvarDecoder;
+
+impl AccountDecoder for SysvarDecoder {
+    fn decode(&self, pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        if *pubkey == clock_id() {
+            let clock: Clock = bincode::deserialize(data)?;
+            return Ok(json!({
+                "type": "sysvar",
+                "name": "clock",
+                "slot": clock.slot,
+                "epoch": clock.epoch,
+                "epoch_start_timestamp": clock.epoch_start_timestamp,
+                "leader_schedule_epoch": clock.leader_schedule_epoch,
+                "unix_timestamp": clock.unix_timestamp,
+            }));
+        }
+
+        if *pubkey == rent_id() {
+            let rent: Rent = bincode::deserialize(data)?;
+            return Ok(json!({
+                "type": "sysvar",
+                "name": "rent",
+                "lamports_per_byte_year": rent.lamports_per_byte_year,
+                "exemption_threshold": rent.exemption_threshold,
+                "burn_percent": rent.burn_percent,
+            }));
+        }
+
+        if *pubkey == epoch_schedule_id() {
+            let schedule: EpochSchedule = bincode::deserialize(data)?;
+            return Ok(json!({
+                "type": "sysvar",
+                "name": "epoch_schedule",
+                "slots_per_epoch": schedule.slots_per_epoch,
+                "leader_schedule_slot_offset": schedule.leader_schedule_slot_offset,
+                "warmup": schedule.warmup,
+                "first_normal_epoch": schedule.first_normal_epoch,
+                "first_normal_slot": schedule.first_normal_slot,
+            }));
+        }
+
+        Ok(json!({
+            "type": "sysvar",
+            "name": "unknown",
+            "hex": hex::encode(data),
+            "size": data.len(),
+        }))
+    }
+}
+
+fn clock_id() -> Pubkey {
+    Pubkey::from_str("SysvarC1ock11111111111111111111111111111111").expect("valid pubkey")
+}
+
+fn rent_id() -> Pubkey {
+    Pubkey::from_str("SysvarRent111111111111111111111111111111111").expect("valid pubkey")
+}
+
+fn epoch_schedule_id() -> Pubkey {
+    Pubkey::from_str("SysvarEpochSchedu1e111111111111111111111111").expect("valid pubkey")
+}
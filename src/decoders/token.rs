@@ -0,0 +1,141 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_client::rpc_client::RpcClient;
+use solana_pubkey::Pubkey;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Byte layout of `spl_token::state::Account` (165 bytes, no padding).
+const TOKEN_ACCOUNT_LEN: usize = 165;
+/// Byte layout of `spl_token::state::Mint` (82 bytes, no padding).
+const MINT_LEN: usize = 82;
+/// Offset of the `decimals` byte in `spl_token::state::Mint`.
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Decodes SPL token accounts and mints. Token accounts report mint, owner,
+/// amount, delegate, state and is_native; mints report their own decimals,
+/// supply and authorities. The on-chain token account only stores the raw
+/// integer `amount`, so each mint is fetched once (and cached) to read its
+/// `decimals` and derive a human-readable `ui_amount`.
+pub struct TokenDecoder {
+    rpc: Rc<RpcClient>,
+    mint_decimals: RefCell<HashMap<Pubkey, u8>>,
+}
+
+impl TokenDecoder {
+    pub fn new(rpc: Rc<RpcClient>) -> Self {
+        Self {
+            rpc,
+            mint_decimals: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn decimals_for(&self, mint: &Pubkey) -> Result<u8> {
+        if let Some(decimals) = self.mint_decimals.borrow().get(mint) {
+            return Ok(*decimals);
+        }
+
+        let mint_account = self.rpc.get_account(mint)?;
+        anyhow::ensure!(
+            mint_account.data.len() > MINT_DECIMALS_OFFSET,
+            "mint account data too short: {} bytes",
+            mint_account.data.len()
+        );
+        let decimals = mint_account.data[MINT_DECIMALS_OFFSET];
+        self.mint_decimals.borrow_mut().insert(*mint, decimals);
+        Ok(decimals)
+    }
+
+    fn decode_token_account(&self, data: &[u8]) -> Result<serde_json::Value> {
+        anyhow::ensure!(
+            data.len() == TOKEN_ACCOUNT_LEN,
+            "not a token account: expected {} bytes, got {} (e.g. a 355-byte \
+             Multisig account is owned by the Token program too)",
+            TOKEN_ACCOUNT_LEN,
+            data.len()
+        );
+
+        let mint = Pubkey::try_from(&data[0..32])?;
+        let owner = Pubkey::try_from(&data[32..64])?;
+        let amount = u64::from_le_bytes(data[64..72].try_into()?);
+        let delegate = read_coption_pubkey(&data[72..108])?;
+        let state = match data[108] {
+            0 => "uninitialized",
+            1 => "initialized",
+            2 => "frozen",
+            other => anyhow::bail!("unknown token account state: {}", other),
+        };
+        let is_native = read_coption_u64(&data[109..121])?;
+        let decimals = self.decimals_for(&mint)?;
+
+        Ok(json!({
+            "type": "token",
+            "mint": mint.to_string(),
+            "owner": owner.to_string(),
+            "amount": amount.to_string(),
+            "decimals": decimals,
+            "ui_amount": format_ui_amount(amount, decimals),
+            "delegate": delegate.map(|pk| pk.to_string()),
+            "state": state,
+            "is_native": is_native,
+        }))
+    }
+
+    fn decode_mint(&self, data: &[u8]) -> Result<serde_json::Value> {
+        let mint_authority = read_coption_pubkey(&data[0..36])?;
+        let supply = u64::from_le_bytes(data[36..44].try_into()?);
+        let decimals = data[44];
+        let is_initialized = data[45] != 0;
+        let freeze_authority = read_coption_pubkey(&data[46..82])?;
+
+        Ok(json!({
+            "type": "mint",
+            "mint_authority": mint_authority.map(|pk| pk.to_string()),
+            "supply": supply.to_string(),
+            "decimals": decimals,
+            "is_initialized": is_initialized,
+            "freeze_authority": freeze_authority.map(|pk| pk.to_string()),
+        }))
+    }
+}
+
+impl AccountDecoder for TokenDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        if data.len() == MINT_LEN {
+            return self.decode_mint(data);
+        }
+        self.decode_token_account(data)
+    }
+}
+
+/// Renders `amount / 10^decimals` as a decimal string, avoiding f64 rounding.
+fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let split = digits.len() - decimals;
+    if decimals == 0 {
+        digits
+    } else {
+        format!("{}.{}", &digits[..split], &digits[split..])
+    }
+}
+
+fn read_coption_pubkey(bytes: &[u8]) -> Result<Option<Pubkey>> {
+    let tag = u32::from_le_bytes(bytes[0..4].try_into()?);
+    match tag {
+        0 => Ok(None),
+        1 => Ok(Some(Pubkey::try_from(&bytes[4..36])?)),
+        other => anyhow::bail!("unexpected COption tag: {}", other),
+    }
+}
+
+fn read_coption_u64(bytes: &[u8]) -> Result<Option<u64>> {
+    let tag = u32::from_le_bytes(bytes[0..4].try_into()?);
+    match tag {
+        0 => Ok(None),
+        1 => Ok(Some(u64::from_le_bytes(bytes[4..12].try_into()?))),
+        other => anyhow::bail!("unexpected COption tag: {}", other),
+    }
+}
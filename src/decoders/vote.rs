@@ -0,0 +1,39 @@
+use super::AccountDecoder;
+use anyhow::Result;
+use serde_json::json;
+use solana_pubkey::Pubkey;
+use solana_vote_interface::state::VoteStateVersions;
+
+/// Decodes native Vote program accounts.
+pub struct VoteDecoder;
+
+impl AccountDecoder for VoteDecoder {
+    fn decode(&self, _pubkey: &Pubkey, data: &[u8]) -> Result<serde_json::Value> {
+        let versioned: VoteStateVersions = bincode::deserialize(data)?;
+        let vote_state = versioned.convert_to_current();
+
+        let epoch_credits: Vec<_> = vote_state
+            .epoch_credits()
+            .iter()
+            .map(|(epoch, credits, prev_credits)| {
+                json!({
+                    "epoch": epoch,
+                    "credits": credits,
+                    "previous_credits": prev_credits,
+                })
+            })
+            .collect();
+
+        Ok(json!({
+            "type": "vote",
+            "node_pubkey": vote_state.node_pubkey.to_string(),
+            "authorized_withdrawer": vote_state.authorized_withdrawer.to_string(),
+            "commission": vote_state.commission,
+            "root_slot": vote_state.root_slot,
+            "last_timestamp_slot": vote_state.last_timestamp.slot,
+            "last_timestamp": vote_state.last_timestamp.timestamp,
+            "vote_count": vote_state.votes.len(),
+            "epoch_credits": epoch_credits,
+        }))
+    }
+}
@@ -1,8 +1,9 @@
+mod decoders;
+
 use anyhow::Result;
-use base64ct::{Base64, Encoding};
 use clap::{Parser, Subcommand};
+use decoders::{get_decoder, owner_registry, raw_account_json};
 use serde_json::json;
-use solana_address_lookup_table_interface::state::AddressLookupTable;
 use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_config::{
     CommitmentConfig, RpcAccountInfoConfig, RpcProgramAccountsConfig, UiAccountEncoding,
@@ -11,6 +12,8 @@ use solana_client::rpc_config::{
 use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
 use solana_client::rpc_response::UiAccount;
 use solana_pubkey::Pubkey;
+use std::io::{BufWriter, Write};
+use std::rc::Rc;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -38,7 +41,9 @@ enum Commands {
         )]
         url: String,
 
-        /// Account type parser (alt) - only works without -d
+        /// Account type parser (alt, token, stake, vote, nonce, config,
+        /// sysvar, or auto to dispatch on each account's owner) - only works
+        /// without -d
         #[arg(short = 't', long, conflicts_with = "data")]
         parser: Option<String>,
 
@@ -46,7 +51,9 @@ enum Commands {
         #[arg(short, long, conflicts_with = "parser")]
         data: Option<String>,
 
-        /// Filter by data at offset (e.g., 10:0x0f0000 or 10:Pubkey58)
+        /// Filter by data at offset (e.g., 10:0x0f0000, 10:Pubkey58, or
+        /// with an explicit encoding: 10:base58:3Mc..., 10:base64:SGVsbG8=,
+        /// 10:hex:0f0000)
         #[arg(short, long)]
         filter: Vec<String>,
 
@@ -54,62 +61,90 @@ enum Commands {
         #[arg(short, long)]
         size: Option<u64>,
 
+        /// RPC account data encoding: base64, base64+zstd, base58, or
+        /// jsonParsed (uses the node's built-in parser, bypassing -t)
+        #[arg(long, default_value = "base64+zstd")]
+        encoding: String,
+
+        /// Output format: json (single pretty-printed document, default) or
+        /// ndjson (stream one compact account object per line, flat memory
+        /// use for large programs)
+        #[arg(long, default_value = "json")]
+        format: String,
+
+        /// Sort accounts by pubkey before processing, for deterministic,
+        /// diffable output across repeated runs against the same program
+        #[arg(long)]
+        ordered: bool,
+
         /// Output JSON file (omit for stdout)
         #[arg(short, long)]
         output: Option<String>,
     },
 }
 
-trait AccountDecoder: Send + Sync {
-    fn decode(&self, data: &[u8]) -> Result<serde_json::Value>;
+#[derive(PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Ndjson,
 }
 
-struct AltDecoder;
-impl AccountDecoder for AltDecoder {
-    fn decode(&self, data: &[u8]) -> Result<serde_json::Value> {
-        let alt = AddressLookupTable::deserialize(data)?;
-        let addresses: Vec<String> = alt
-            .addresses
-            .as_ref()
-            .iter()
-            .map(|pk| pk.to_string())
-            .collect();
-
-        Ok(json!({
-            "type": "address_lookup_table",
-            "addresses": addresses,
-            "num_addresses": addresses.len(),
-        }))
+fn parse_format(format: &str) -> Result<OutputFormat> {
+    match format {
+        "json" => Ok(OutputFormat::Json),
+        "ndjson" => Ok(OutputFormat::Ndjson),
+        other => anyhow::bail!("Unknown format: {}", other),
     }
 }
 
-fn get_decoder(parser: Option<&str>) -> Result<Option<Box<dyn AccountDecoder>>> {
-    match parser {
-        Some("alt") => Ok(Some(Box::new(AltDecoder))),
-        Some(p) => anyhow::bail!("Unknown parser: {}", p),
-        None => Ok(None),
+fn parse_encoding(encoding: &str) -> Result<UiAccountEncoding> {
+    match encoding {
+        "base64" => Ok(UiAccountEncoding::Base64),
+        "base64+zstd" => Ok(UiAccountEncoding::Base64Zstd),
+        "base58" => Ok(UiAccountEncoding::Base58),
+        "jsonParsed" => Ok(UiAccountEncoding::JsonParsed),
+        other => anyhow::bail!("Unknown encoding: {}", other),
     }
 }
 
+/// Parses a `-f` filter of the form `offset:data` or `offset:encoding:data`.
+/// Supported encodings are `hex`, `base58` and `base64`; without one, `0x`
+/// prefixed data is treated as hex and everything else as a base58 pubkey.
 fn parse_filter(filter: &str) -> Result<RpcFilterType> {
-    let parts: Vec<&str> = filter.split(':').collect();
-    anyhow::ensure!(parts.len() == 2, "Filter must be offset:data");
+    let parts: Vec<&str> = filter.splitn(3, ':').collect();
 
-    let offset: usize = parts[0].parse()?;
-    let data_str = parts[1];
+    let (offset, encoded_bytes) = match parts.as_slice() {
+        [offset, encoding @ ("base58" | "base64" | "hex"), data] => {
+            (*offset, encode_filter_bytes(encoding, data)?)
+        }
+        [offset, data] => (*offset, default_filter_bytes(data)?),
+        _ => anyhow::bail!("Filter must be offset:data or offset:encoding:data"),
+    };
+
+    let offset: usize = offset.parse()?;
+
+    Ok(RpcFilterType::Memcmp(Memcmp::new(offset, encoded_bytes)))
+}
 
-    let bytes = if data_str.starts_with("0x") {
-        hex::decode(&data_str[2..])?
+fn encode_filter_bytes(encoding: &str, data_str: &str) -> Result<MemcmpEncodedBytes> {
+    match encoding {
+        "hex" => Ok(MemcmpEncodedBytes::Bytes(hex::decode(data_str)?)),
+        "base58" => Ok(MemcmpEncodedBytes::Base58(data_str.to_string())),
+        "base64" => Ok(MemcmpEncodedBytes::Base64(data_str.to_string())),
+        other => anyhow::bail!("Unknown filter encoding: {}", other),
+    }
+}
+
+fn default_filter_bytes(data_str: &str) -> Result<MemcmpEncodedBytes> {
+    let bytes = if let Some(hex_str) = data_str.strip_prefix("0x") {
+        hex::decode(hex_str)?
     } else {
         // Try base58 (pubkey)
         let pubkey = Pubkey::from_str(data_str)?;
         pubkey.to_bytes().to_vec()
     };
 
-    Ok(RpcFilterType::Memcmp(Memcmp::new(
-        offset,
-        MemcmpEncodedBytes::Bytes(bytes),
-    )))
+    Ok(MemcmpEncodedBytes::Bytes(bytes))
 }
 
 fn main() -> Result<()> {
@@ -123,13 +158,16 @@ fn main() -> Result<()> {
             data,
             filter,
             size,
+            encoding,
+            format,
+            ordered,
             output,
         } => {
-            let rpc = RpcClient::new_with_timeout_and_commitment(
+            let rpc = Rc::new(RpcClient::new_with_timeout_and_commitment(
                 url,
                 Duration::from_secs(15 * 60),
                 CommitmentConfig::processed(),
-            );
+            ));
 
             let program_pubkey = Pubkey::from_str(&program)?;
 
@@ -155,9 +193,12 @@ fn main() -> Result<()> {
                 rpc_filters.push(RpcFilterType::DataSize(size_val));
             }
 
+            let account_encoding = parse_encoding(&encoding)?;
+            let json_parsed = matches!(account_encoding, UiAccountEncoding::JsonParsed);
+
             let cfg = RpcProgramAccountsConfig {
                 account_config: RpcAccountInfoConfig {
-                    encoding: Some(UiAccountEncoding::Base64Zstd),
+                    encoding: Some(account_encoding),
                     data_slice,
                     ..Default::default()
                 },
@@ -169,22 +210,67 @@ fn main() -> Result<()> {
                 ..Default::default()
             };
 
-            let accounts: Vec<(Pubkey, UiAccount)> =
+            let mut accounts: Vec<(Pubkey, UiAccount)> =
                 rpc.get_program_ui_accounts_with_config(&program_pubkey, cfg)?;
 
             eprintln!("Fetched {} accounts", accounts.len());
 
-            let decoder = get_decoder(parser.as_deref())?;
+            if ordered {
+                accounts.sort_by(|(a, _), (b, _)| a.cmp(b));
+            }
+
+            let format = parse_format(&format)?;
+
+            let auto = parser.as_deref() == Some("auto");
+            let decoder = if auto {
+                None
+            } else {
+                get_decoder(parser.as_deref(), Rc::clone(&rpc))?
+            };
+            let registry = if auto {
+                Some(owner_registry(Rc::clone(&rpc)))
+            } else {
+                None
+            };
+            let mut ndjson_sink: Option<Box<dyn Write>> = if format == OutputFormat::Ndjson {
+                Some(match &output {
+                    Some(file) => Box::new(BufWriter::new(std::fs::File::create(file)?)),
+                    None => Box::new(BufWriter::new(std::io::stdout())),
+                })
+            } else {
+                None
+            };
+
             let mut results = Vec::new();
             let mut processed = 0;
 
             for (pubkey, acc) in accounts {
-                let data_value = if let Some(ref dec) = decoder {
+                let data_value = if json_parsed {
+                    // The node already parsed this account; use its JSON
+                    // verbatim instead of running a local decoder.
+                    serde_json::to_value(&acc.data)?
+                } else if let Some(registry) = &registry {
+                    // Dispatch on owner, falling back to raw output.
+                    let Some(data) = acc.data.decode() else {
+                        continue;
+                    };
+                    let owner = Pubkey::from_str(&acc.owner)?;
+                    match registry.get(&owner) {
+                        Some(dec) => match dec.decode(&pubkey, &data) {
+                            Ok(decoded) => decoded,
+                            Err(e) => {
+                                eprintln!("Failed to decode {}: {}", pubkey, e);
+                                continue;
+                            }
+                        },
+                        None => raw_account_json(&data),
+                    }
+                } else if let Some(ref dec) = decoder {
                     // Full decode if parser specified
                     let Some(data) = acc.data.decode() else {
                         continue;
                     };
-                    match dec.decode(&data) {
+                    match dec.decode(&pubkey, &data) {
                         Ok(decoded) => decoded,
                         Err(e) => {
                             eprintln!("Failed to decode {}: {}", pubkey, e);
@@ -196,36 +282,44 @@ fn main() -> Result<()> {
                     let Some(data) = acc.data.decode() else {
                         continue;
                     };
-                    json!({
-                        "type": "raw",
-                        "hex": hex::encode(&data),
-                        "base64": Base64::encode_string(&data),
-                        "size": data.len(),
-                    })
+                    raw_account_json(&data)
                 };
 
-                results.push(json!({
+                let account_json = json!({
                     "pubkey": pubkey.to_string(),
                     "lamports": acc.lamports,
                     "owner": acc.owner,
                     "data": data_value,
-                }));
+                });
+
+                if let Some(sink) = &mut ndjson_sink {
+                    writeln!(sink, "{}", account_json)?;
+                } else {
+                    results.push(account_json);
+                }
                 processed += 1;
             }
 
             eprintln!("Processed: {}", processed);
 
-            let output_json = json!({
-                "program": program,
-                "count": processed,
-                "accounts": results,
-            });
-
-            if let Some(file) = output {
-                std::fs::write(&file, serde_json::to_string_pretty(&output_json)?)?;
-                eprintln!("Saved to {}", file);
+            if let Some(mut sink) = ndjson_sink {
+                sink.flush()?;
+                if let Some(file) = &output {
+                    eprintln!("Saved to {}", file);
+                }
             } else {
-                println!("{}", serde_json::to_string_pretty(&output_json)?);
+                let output_json = json!({
+                    "program": program,
+                    "count": processed,
+                    "accounts": results,
+                });
+
+                if let Some(file) = output {
+                    std::fs::write(&file, serde_json::to_string_pretty(&output_json)?)?;
+                    eprintln!("Saved to {}", file);
+                } else {
+                    println!("{}", serde_json::to_string_pretty(&output_json)?);
+                }
             }
 
             Ok(())